@@ -0,0 +1,177 @@
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+use crate::{Address, ExtraTags, Place};
+
+/// Alternate Nominatim response formats beyond the default `json`.
+///
+/// Nominatim also serves `geocodejson`, `gpx`, and `xml`, but only `json`
+/// and `geojson` are wired up to a [`Client`](crate::Client) method so far;
+/// the others aren't exposed here until they are.
+///
+/// [Documentation](https://nominatim.org/release-docs/develop/api/Output/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    GeoJson,
+}
+
+impl OutputFormat {
+    pub(crate) fn as_query_value(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::GeoJson => "geojson",
+        }
+    }
+}
+
+/// A GeoJSON geometry, returned when `polygon_geojson` is requested or a
+/// `geojson` output format is used.
+///
+/// [Documentation](https://nominatim.org/release-docs/develop/api/Output/#polygon-output)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum Geometry {
+    Point([f64; 2]),
+    LineString(Vec<[f64; 2]>),
+    Polygon(Vec<Vec<[f64; 2]>>),
+    MultiPolygon(Vec<Vec<Vec<[f64; 2]>>>),
+}
+
+/// A `geojson` search/reverse response: a GeoJSON `FeatureCollection` of [`Feature`]s.
+///
+/// [Documentation](https://nominatim.org/release-docs/develop/api/Output/#geojson)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureCollection {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub features: Vec<Feature>,
+}
+
+/// A single GeoJSON `Feature`, wrapping a [`GeoJsonProperties`] alongside its
+/// `geometry`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Feature {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub properties: GeoJsonProperties,
+    pub geometry: Geometry,
+}
+
+/// The `properties` of a `geojson`-format [`Feature`].
+///
+/// This is *not* the same shape as [`Place`]: the `geojson` output moves
+/// coordinates into [`Feature::geometry`] and reports the feature's class
+/// under `category` rather than `class`.
+///
+/// [Documentation](https://nominatim.org/release-docs/develop/api/Output/#geojson)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeoJsonProperties {
+    #[serde(default)]
+    pub place_id: usize,
+    #[serde(default)]
+    pub osm_type: String,
+    #[serde(default)]
+    pub osm_id: usize,
+    #[serde(default)]
+    pub display_name: String,
+    pub place_rank: Option<usize>,
+    pub category: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: Option<String>,
+    pub importance: Option<f64>,
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub address: Option<Address>,
+    pub extratags: Option<ExtraTags>,
+}
+
+/// Render [`Place`]s as a GPX document of waypoints, using `display_name`
+/// for the waypoint name and the parsed latitude/longitude for its position.
+///
+/// [Documentation](https://www.topografix.com/gpx.asp)
+pub fn to_gpx(places: &[Place]) -> String {
+    let mut gpx = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<gpx version=\"1.1\" creator=\"nominatim-rs\">\n");
+
+    for place in places {
+        gpx.push_str(&format!(
+            "  <wpt lat=\"{}\" lon=\"{}\"><name>{}</name></wpt>\n",
+            place.lat,
+            place.lon,
+            escape_xml(&place.display_name),
+        ));
+    }
+
+    gpx.push_str("</gpx>\n");
+    gpx
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Nominatim encodes coordinates as JSON strings rather than numbers; parse
+/// one into an [`f64`].
+pub(crate) fn deserialize_f64_str<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(D::Error::custom)
+}
+
+/// As [`deserialize_f64_str`], but for the `boundingbox` array of strings.
+pub(crate) fn deserialize_f64_vec_str<'de, D>(deserializer: D) -> Result<Vec<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|s| s.parse().map_err(D::Error::custom))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn place(display_name: &str, lat: f64, lon: f64) -> Place {
+        Place {
+            place_id: 0,
+            licence: String::new(),
+            osm_type: String::new(),
+            osm_id: 0,
+            boundingbox: Vec::new(),
+            lat,
+            lon,
+            display_name: display_name.to_string(),
+            class: None,
+            _type: None,
+            importance: None,
+            icon: None,
+            address: None,
+            extratags: None,
+            geojson: None,
+        }
+    }
+
+    #[test]
+    fn escape_xml_escapes_all_five_entities() {
+        assert_eq!(
+            escape_xml(r#"Tom & Jerry's <"diner">"#),
+            "Tom &amp; Jerry&apos;s &lt;&quot;diner&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn to_gpx_renders_a_waypoint_per_place() {
+        let gpx = to_gpx(&[place("Tom & Jerry's Cafe", 48.1, 11.5)]);
+        assert!(gpx.contains(r#"<wpt lat="48.1" lon="11.5"><name>Tom &amp; Jerry&apos;s Cafe</name></wpt>"#));
+        assert!(gpx.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(gpx.trim_end().ends_with("</gpx>"));
+    }
+}
@@ -1,30 +1,90 @@
+use std::fmt;
+
 /// Which method to access the nominatim API.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum IdentificationMethod {
-    Referer(String),
-    UserAgent(String),
+    Referer(Secret),
+    UserAgent(Secret),
+    /// Sent as a `key=` query parameter, for commercial Nominatim-compatible
+    /// endpoints (LocationIQ, MapTiler, self-hosted gateways) that
+    /// authenticate this way.
+    ApiKey(Secret),
+    /// Sent as an `Authorization: Bearer <token>` header.
+    Bearer(Secret),
+}
+
+impl fmt::Debug for IdentificationMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant = match self {
+            Self::Referer(_) => "Referer",
+            Self::UserAgent(_) => "UserAgent",
+            Self::ApiKey(_) => "ApiKey",
+            Self::Bearer(_) => "Bearer",
+        };
+        write!(f, "{variant}([redacted])")
+    }
 }
 
 impl IdentificationMethod {
-    pub fn header(&self) -> &'static str {
+    /// The HTTP header this method is sent as, or `None` for [`Self::ApiKey`],
+    /// which is sent as a query parameter instead.
+    pub fn header(&self) -> Option<&'static str> {
         match self {
-            Self::Referer(_) => "Referer",
-            Self::UserAgent(_) => "User-Agent",
+            Self::Referer(_) => Some("Referer"),
+            Self::UserAgent(_) => Some("User-Agent"),
+            Self::ApiKey(_) => None,
+            Self::Bearer(_) => Some("Authorization"),
         }
     }
 
     pub fn value(self) -> String {
         match self {
-            Self::Referer(value) => value,
-            Self::UserAgent(value) => value,
+            Self::Referer(secret) | Self::UserAgent(secret) | Self::ApiKey(secret) => {
+                secret.expose().to_string()
+            }
+            Self::Bearer(secret) => format!("Bearer {}", secret.expose()),
+        }
+    }
+
+    /// The `key=` query parameter to send alongside the existing format
+    /// params, for [`Self::ApiKey`].
+    pub(crate) fn query_param(&self) -> Option<(&'static str, String)> {
+        match self {
+            Self::ApiKey(secret) => Some(("key", secret.expose().to_string())),
+            _ => None,
         }
     }
 
     pub fn from_referer(s: impl AsRef<str>) -> Self {
-        Self::Referer(s.as_ref().to_string())
+        Self::Referer(Secret::new(s.as_ref()))
     }
 
     pub fn from_user_agent(s: impl AsRef<str>) -> Self {
-        Self::UserAgent(s.as_ref().to_string())
+        Self::UserAgent(Secret::new(s.as_ref()))
+    }
+
+    /// Authenticate with an API key, sent as a `key=` query parameter.
+    pub fn from_api_key(s: impl AsRef<str>) -> Self {
+        Self::ApiKey(Secret::new(s.as_ref()))
+    }
+
+    /// Authenticate with an `Authorization: Bearer <token>` header.
+    pub fn from_bearer(s: impl AsRef<str>) -> Self {
+        Self::Bearer(Secret::new(s.as_ref()))
+    }
+}
+
+/// A string value that is redacted from [`fmt::Debug`] output so credentials
+/// don't end up in logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    fn new(s: impl Into<String>) -> Self {
+        Self(s.into())
+    }
+
+    fn expose(&self) -> &str {
+        &self.0
     }
 }
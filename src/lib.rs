@@ -9,9 +9,15 @@ use gloo::net::{self, http::{Request, Headers}};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use url::Url;
 
+mod format;
 mod ident;
+mod rate_limit;
+mod search;
 
+pub use format::{to_gpx, Feature, FeatureCollection, GeoJsonProperties, Geometry, OutputFormat};
 pub use ident::IdentificationMethod;
+pub use rate_limit::RetryPolicy;
+pub use search::{SearchBuilder, SearchOptions};
 
 #[cfg(all(feature = "reqwest", feature = "wasm"))]
 compile_error!("Features \"reqwest\" and \"wasm\" are mutually exclusive - did you forget to disable default features for nominatim?");
@@ -22,19 +28,92 @@ type HttpClient = reqwest::Client;
 type HttpClient = ();
 
 #[cfg(feature = "reqwest")]
-pub type Error = reqwest::Error;
+type HttpError = reqwest::Error;
 #[cfg(feature = "wasm")]
-pub type Error = net::Error;
+type HttpError = net::Error;
 
+/// A closure that can mutate an outgoing request before it is sent. See
+/// [`Client::with_interceptor`].
+#[cfg(feature = "reqwest")]
+type Interceptor = std::sync::Arc<dyn Fn(reqwest::RequestBuilder) -> reqwest::RequestBuilder + Send + Sync>;
+#[cfg(feature = "wasm")]
+type Interceptor = std::rc::Rc<dyn Fn(gloo::net::http::RequestBuilder) -> gloo::net::http::RequestBuilder>;
+
+/// Errors returned by [`Client`] methods.
+#[derive(Debug)]
+pub enum Error {
+    /// The HTTP request itself failed (connection, timeout, TLS, ...).
+    Http(HttpError),
+    /// The response body couldn't be parsed into the expected type, either
+    /// because it wasn't JSON or because it didn't match the expected shape.
+    Deserialization {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// The Nominatim server responded with an explicit `{"error": ...}` payload.
+    Nominatim { error: String },
+    /// The server kept responding `429`/`503` until [`RetryPolicy::max_attempts`]
+    /// was exhausted. `body` is the raw, unparsed final response: a
+    /// rate-limited response is frequently HTML or plain text from a proxy
+    /// rather than JSON, so it isn't worth routing through [`Self::Deserialization`].
+    RateLimited { status: u16, body: String },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Http(source) => write!(f, "http request failed: {source}"),
+            Error::Deserialization { source, body } => {
+                write!(f, "failed to parse response body: {source} (body: {body:?})")
+            }
+            Error::Nominatim { error } => write!(f, "nominatim error: {error}"),
+            Error::RateLimited { status, .. } => {
+                write!(f, "giving up after exhausting retries on a {status} response")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http(source) => Some(source),
+            Error::Deserialization { source, .. } => Some(source),
+            Error::Nominatim { .. } => None,
+            Error::RateLimited { .. } => None,
+        }
+    }
+}
+
+impl From<HttpError> for Error {
+    fn from(source: HttpError) -> Self {
+        Error::Http(source)
+    }
+}
 
 /// The interface for accessing a Nominatim API server.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Client {
     ident: Option<IdentificationMethod>, // how to access the server
     base_url: Url,               // defaults to https://nominatim.openstreetmap.org
     client: HttpClient,
     /// HTTP Request Timeout [`Duration`]
     pub timeout: Duration,
+    interceptor: Option<Interceptor>,
+    rate_limiter: rate_limit::RateLimiter,
+    retry_policy: RetryPolicy,
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("ident", &self.ident)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("interceptor", &self.interceptor.is_some())
+            .field("retry_policy", &self.retry_policy)
+            .finish()
+    }
 }
 
 impl Client {
@@ -55,13 +134,68 @@ impl Client {
             base_url: Url::parse("https://nominatim.openstreetmap.org/").unwrap(),
             client,
             timeout,
+            interceptor: None,
+            rate_limiter: rate_limit::new_rate_limiter(Duration::from_secs(1)),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    /// Set the minimum interval enforced between outgoing requests (default:
+    /// 1 second, matching the public nominatim.openstreetmap.org usage
+    /// policy). Concurrent calls on a cloned [`Client`] serialize to at most
+    /// one request per interval.
+    #[cfg(feature = "reqwest")]
+    pub fn set_rate_limit(&mut self, interval: Duration) {
+        self.rate_limiter = rate_limit::new_rate_limiter(interval);
+    }
+
+    /// Set the minimum interval enforced between outgoing requests.
+    ///
+    /// No-op under the `wasm` backend, which has no shared runtime to
+    /// serialize dispatch through.
+    #[cfg(feature = "wasm")]
+    pub fn set_rate_limit(&mut self, _interval: Duration) {}
+
+    /// Configure retry behavior for `429`/`503` responses.
+    ///
+    /// Only the `reqwest` backend retries; under the `wasm` backend each
+    /// request is sent once and the policy is ignored.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
     pub fn set_ident(&mut self, ident: IdentificationMethod) {
         self.ident = Some(ident);
     }
 
+    /// Register a closure that can mutate every outgoing request before it is
+    /// sent, e.g. to attach auth tokens, tracing headers, or route through a
+    /// proxy.
+    ///
+    /// The closure runs inside `fetch` after the base URL and
+    /// [`IdentificationMethod`] headers have already been applied.
+    #[cfg(feature = "reqwest")]
+    pub fn with_interceptor<F>(&mut self, interceptor: F)
+    where
+        F: Fn(reqwest::RequestBuilder) -> reqwest::RequestBuilder + Send + Sync + 'static,
+    {
+        self.interceptor = Some(std::sync::Arc::new(interceptor));
+    }
+
+    /// Register a closure that can mutate every outgoing request before it is
+    /// sent, e.g. to attach auth tokens, tracing headers, or route through a
+    /// proxy.
+    ///
+    /// The closure runs inside `fetch` after the base URL and
+    /// [`IdentificationMethod`] headers have already been applied.
+    #[cfg(feature = "wasm")]
+    pub fn with_interceptor<F>(&mut self, interceptor: F)
+    where
+        F: Fn(gloo::net::http::RequestBuilder) -> gloo::net::http::RequestBuilder + 'static,
+    {
+        self.interceptor = Some(std::rc::Rc::new(interceptor));
+    }
+
     /// Set the client's internal base url for all requests.
     pub fn set_base_url<U: TryInto<Url>>(&mut self, url: U) -> Result<(), U::Error> {
         self.base_url = url.try_into()?;
@@ -69,6 +203,24 @@ impl Client {
         Ok(())
     }
 
+    /// Append the `key=` query parameter for [`IdentificationMethod::ApiKey`], if set.
+    fn apply_api_key(&self, url: &mut Url) {
+        if let Some((key, value)) = self.ident.as_ref().and_then(IdentificationMethod::query_param) {
+            // Endpoint methods build their query with a trailing `&` when the
+            // options they interpolate encode to an empty string (the
+            // default-options case). `query_pairs_mut` always joins with its
+            // own `&`, so trim that dangling one first to avoid `...&&key=`.
+            if let Some(query) = url.query() {
+                if query.ends_with('&') {
+                    let trimmed = query.trim_end_matches('&').to_string();
+                    url.set_query(Some(&trimmed));
+                }
+            }
+
+            url.query_pairs_mut().append_pair(key, &value);
+        }
+    }
+
     /// Check the status of the nominatim server.
     ///
     /// [Documentation](https://nominatim.org/release-docs/develop/api/Status/)
@@ -76,25 +228,100 @@ impl Client {
         let mut url = self.base_url.join("status.php").unwrap();
         url.set_query(Some("format=json"));
 
-        let headers = self.ident.clone().map(|hs| mk_headers(hs));
+        self.apply_api_key(&mut url);
+
+        let headers = self.ident.clone().and_then(mk_headers);
 
-        fetch(&self.client, url, self.timeout, headers).await
+        fetch(
+            &self.client,
+            url,
+            self.timeout,
+            headers,
+            self.interceptor.clone(),
+            &self.rate_limiter,
+            &self.retry_policy,
+        )
+        .await
     }
 
     /// Get [`Place`]s from a search query.
     ///
     /// [Documentation](https://nominatim.org/release-docs/develop/api/Search/)
     pub async fn search(&self, query: impl AsRef<str>) -> Result<Vec<Place>, Error> {
+        self.search_with_options(query.as_ref(), &SearchOptions::default())
+            .await
+    }
+
+    /// Start building a [`SearchBuilder`] for `query`, allowing limit,
+    /// country, viewbox, and other search parameters to be set before
+    /// sending the request with [`SearchBuilder::send`].
+    ///
+    /// [Documentation](https://nominatim.org/release-docs/develop/api/Search/)
+    pub fn search_builder(&self, query: impl Into<String>) -> SearchBuilder {
+        SearchBuilder::new(self.clone(), query)
+    }
+
+    /// Get [`Place`]s from a search query, applying the given [`SearchOptions`].
+    ///
+    /// [Documentation](https://nominatim.org/release-docs/develop/api/Search/)
+    pub async fn search_with_options(
+        &self,
+        query: impl AsRef<str>,
+        options: &SearchOptions,
+    ) -> Result<Vec<Place>, Error> {
         let mut url = self.base_url.clone();
         url.set_query(Some(&format!(
-            "addressdetails=1&extratags=1&q={}&format=json",
-            // query.as_ref().replace(' ', "+")
-            urlencoding::encode(query.as_ref())
+            "addressdetails=1&extratags=1&q={}&format=json&{}",
+            urlencoding::encode(query.as_ref()),
+            serde_urlencoded::to_string(options).expect("couldn't encode params as urlencoded")
         )));
 
-        let headers = self.ident.clone().map(|hs| mk_headers(hs));
+        self.apply_api_key(&mut url);
+
+        let headers = self.ident.clone().and_then(mk_headers);
 
-        fetch(&self.client, url, self.timeout, headers).await
+        fetch(
+            &self.client,
+            url,
+            self.timeout,
+            headers,
+            self.interceptor.clone(),
+            &self.rate_limiter,
+            &self.retry_policy,
+        )
+        .await
+    }
+
+    /// Get a GeoJSON [`FeatureCollection`] from a search query.
+    ///
+    /// [Documentation](https://nominatim.org/release-docs/develop/api/Output/#geojson)
+    pub async fn search_geojson(
+        &self,
+        query: impl AsRef<str>,
+        options: &SearchOptions,
+    ) -> Result<FeatureCollection, Error> {
+        let mut url = self.base_url.clone();
+        url.set_query(Some(&format!(
+            "addressdetails=1&extratags=1&q={}&format={}&{}",
+            urlencoding::encode(query.as_ref()),
+            OutputFormat::GeoJson.as_query_value(),
+            serde_urlencoded::to_string(options).expect("couldn't encode params as urlencoded")
+        )));
+
+        self.apply_api_key(&mut url);
+
+        let headers = self.ident.clone().and_then(mk_headers);
+
+        fetch(
+            &self.client,
+            url,
+            self.timeout,
+            headers,
+            self.interceptor.clone(),
+            &self.rate_limiter,
+            &self.retry_policy,
+        )
+        .await
     }
 
     /// Get [`Place`]s from a structured search query.
@@ -109,9 +336,20 @@ impl Client {
             serde_urlencoded::to_string(params).expect("couldn't encode params as urlencoded")
         )));
 
-        let headers = self.ident.clone().map(|hs| mk_headers(hs));
+        self.apply_api_key(&mut url);
+
+        let headers = self.ident.clone().and_then(mk_headers);
 
-        fetch(&self.client, url, self.timeout, headers).await
+        fetch(
+            &self.client,
+            url,
+            self.timeout,
+            headers,
+            self.interceptor.clone(),
+            &self.rate_limiter,
+            &self.retry_policy,
+        )
+        .await
     }
 
     /// Generate a [`Place`] from latitude and longitude.
@@ -122,52 +360,150 @@ impl Client {
         latitude: impl AsRef<str>,
         longitude: impl AsRef<str>,
         zoom: Option<u8>,
-    ) -> Result<Option<Place>, Error> {
+    ) -> Result<Place, Error> {
+        self.reverse_with_options(latitude, longitude, zoom, &SearchOptions::default())
+            .await
+    }
+
+    /// Generate a [`Place`] from latitude and longitude, applying the given [`SearchOptions`].
+    ///
+    /// [Documentation](https://nominatim.org/release-docs/develop/api/Reverse/)
+    pub async fn reverse_with_options(
+        &self,
+        latitude: impl AsRef<str>,
+        longitude: impl AsRef<str>,
+        zoom: Option<u8>,
+        options: &SearchOptions,
+    ) -> Result<Place, Error> {
         let mut url = self.base_url.join("reverse").unwrap();
 
+        let params = serde_urlencoded::to_string(options).expect("couldn't encode params as urlencoded");
         match zoom {
             Some(zoom) => {
                 url.set_query(Some(&format!(
-                    "addressdetails=1&extratags=1&format=json&lat={}&lon={}&zoom={}",
+                    "addressdetails=1&extratags=1&format=json&lat={}&lon={}&zoom={}&{}",
                     latitude.as_ref().replace(' ', ""),
                     longitude.as_ref().replace(' ', ""),
-                    zoom
+                    zoom,
+                    params,
                 )));
             }
             None => {
                 url.set_query(Some(&format!(
-                    "addressdetails=1&extratags=1&format=json&lat={}&lon={}",
+                    "addressdetails=1&extratags=1&format=json&lat={}&lon={}&{}",
                     latitude.as_ref().replace(' ', ""),
                     longitude.as_ref().replace(' ', ""),
+                    params,
                 )));
             }
         }
 
-        let headers = self.ident.clone().map(|hs| mk_headers(hs));
+        self.apply_api_key(&mut url);
+
+        let headers = self.ident.clone().and_then(mk_headers);
+
+        fetch(
+            &self.client,
+            url,
+            self.timeout,
+            headers,
+            self.interceptor.clone(),
+            &self.rate_limiter,
+            &self.retry_policy,
+        )
+        .await
+    }
+
+    /// Generate a GeoJSON [`FeatureCollection`] from latitude and longitude.
+    ///
+    /// [Documentation](https://nominatim.org/release-docs/develop/api/Output/#geojson)
+    pub async fn reverse_geojson(
+        &self,
+        latitude: impl AsRef<str>,
+        longitude: impl AsRef<str>,
+        zoom: Option<u8>,
+        options: &SearchOptions,
+    ) -> Result<FeatureCollection, Error> {
+        let mut url = self.base_url.join("reverse").unwrap();
 
-        let res: Either<ErrorResponse, Place> =
-            fetch(&self.client, url, self.timeout, headers).await?;
-        match res {
-            Either::Left(_) => Ok(None),
-            Either::Right(x) => Ok(Some(x)),
+        let params = serde_urlencoded::to_string(options).expect("couldn't encode params as urlencoded");
+        let output_format = OutputFormat::GeoJson.as_query_value();
+        match zoom {
+            Some(zoom) => {
+                url.set_query(Some(&format!(
+                    "addressdetails=1&extratags=1&format={}&lat={}&lon={}&zoom={}&{}",
+                    output_format,
+                    latitude.as_ref().replace(' ', ""),
+                    longitude.as_ref().replace(' ', ""),
+                    zoom,
+                    params,
+                )));
+            }
+            None => {
+                url.set_query(Some(&format!(
+                    "addressdetails=1&extratags=1&format={}&lat={}&lon={}&{}",
+                    output_format,
+                    latitude.as_ref().replace(' ', ""),
+                    longitude.as_ref().replace(' ', ""),
+                    params,
+                )));
+            }
         }
+
+        self.apply_api_key(&mut url);
+
+        let headers = self.ident.clone().and_then(mk_headers);
+
+        fetch(
+            &self.client,
+            url,
+            self.timeout,
+            headers,
+            self.interceptor.clone(),
+            &self.rate_limiter,
+            &self.retry_policy,
+        )
+        .await
     }
 
     /// Return [`Place`]s from a list of OSM Node, Way, or Relations.
     ///
     /// [Documentation](https://nominatim.org/release-docs/develop/api/Lookup/)
     pub async fn lookup(&self, queries: Vec<&str>) -> Result<Vec<Place>, Error> {
+        self.lookup_with_options(queries, &SearchOptions::default()).await
+    }
+
+    /// Return [`Place`]s from a list of OSM Node, Way, or Relations, applying the given [`SearchOptions`].
+    ///
+    /// [Documentation](https://nominatim.org/release-docs/develop/api/Lookup/)
+    pub async fn lookup_with_options(
+        &self,
+        queries: Vec<&str>,
+        options: &SearchOptions,
+    ) -> Result<Vec<Place>, Error> {
         let queries = queries.join(",");
 
         let mut url = self.base_url.join("lookup").unwrap();
         url.set_query(Some(&format!(
-            "osm_ids={}&addressdetails=1&extratags=1&format=json",
-            queries
+            "osm_ids={}&addressdetails=1&extratags=1&format=json&{}",
+            queries,
+            serde_urlencoded::to_string(options).expect("couldn't encode params as urlencoded")
         )));
 
-        let headers = self.ident.clone().map(|hs| mk_headers(hs));
+        self.apply_api_key(&mut url);
 
-        fetch(&self.client, url, self.timeout, headers).await
+        let headers = self.ident.clone().and_then(mk_headers);
+
+        fetch(
+            &self.client,
+            url,
+            self.timeout,
+            headers,
+            self.interceptor.clone(),
+            &self.rate_limiter,
+            &self.retry_policy,
+        )
+        .await
     }
 }
 
@@ -192,12 +528,12 @@ pub struct Place {
     pub osm_type: String,
     #[serde(default)]
     pub osm_id: usize,
-    #[serde(default)]
-    pub boundingbox: Vec<String>,
-    #[serde(default)]
-    pub lat: String,
-    #[serde(default)]
-    pub lon: String,
+    #[serde(default, deserialize_with = "format::deserialize_f64_vec_str")]
+    pub boundingbox: Vec<f64>,
+    #[serde(default, deserialize_with = "format::deserialize_f64_str")]
+    pub lat: f64,
+    #[serde(default, deserialize_with = "format::deserialize_f64_str")]
+    pub lon: f64,
     #[serde(default)]
     pub display_name: String,
     pub class: Option<String>,
@@ -208,6 +544,9 @@ pub struct Place {
     #[serde(default)]
     pub address: Option<Address>,
     pub extratags: Option<ExtraTags>,
+    /// Polygon geometry, present when `polygon_geojson` is requested.
+    #[serde(default)]
+    pub geojson: Option<Geometry>,
 }
 
 /// An address for a place.
@@ -234,24 +573,23 @@ pub struct ExtraTags {
 }
 
 #[cfg(feature = "reqwest")]
-fn mk_headers(ident: IdentificationMethod) -> HeaderMap {
+fn mk_headers(ident: IdentificationMethod) -> Option<HeaderMap> {
+    let header = ident.header()?;
     let mut hs = HeaderMap::new();
     hs.append(
-        HeaderName::from_str(ident.header())
+        HeaderName::from_str(header)
             .expect("invalid nominatim auth header name"),
         HeaderValue::from_str(&ident.value())
             .expect("invalid nominatim auth header value"),
     );
-    hs
+    Some(hs)
 }
 #[cfg(feature = "wasm")]
-fn mk_headers(ident: IdentificationMethod) -> Headers {
+fn mk_headers(ident: IdentificationMethod) -> Option<Headers> {
+    let header = ident.header()?;
     let hs = Headers::new();
-    hs.append(
-        ident.header(),
-        &ident.value(),
-    );
-    hs
+    hs.append(header, &ident.value());
+    Some(hs)
 }
 
 
@@ -260,22 +598,59 @@ async fn fetch<T>(
     client: &HttpClient,
     url: Url,
     timeout: Duration,
-    headers: Option<HeaderMap>
+    headers: Option<HeaderMap>,
+    interceptor: Option<Interceptor>,
+    rate_limiter: &rate_limit::RateLimiter,
+    retry_policy: &RetryPolicy,
 ) -> Result<T, Error>
 where
     T: DeserializeOwned,
 {
-    let mut req = client
-        .get(url);
-    if let Some(headers) = headers {
-        req = req.headers(headers);
+    let mut backoff = retry_policy.initial_backoff;
+
+    for attempt in 0.. {
+        rate_limit::throttle(rate_limiter).await;
+
+        let mut req = client
+            .get(url.clone())
+            .timeout(timeout);
+        if let Some(headers) = headers.clone() {
+            req = req.headers(headers);
+        }
+        if let Some(interceptor) = interceptor.clone() {
+            req = interceptor(req);
+        }
+
+        let response = req.send().await?;
+        let status = response.status();
+
+        if status.as_u16() == 429 || status.as_u16() == 503 {
+            if attempt < retry_policy.max_attempts {
+                let wait = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .map(Duration::from_secs)
+                    .unwrap_or(backoff);
+
+                tokio::time::sleep(wait).await;
+                backoff = rate_limit::next_backoff(backoff, retry_policy.max_backoff);
+                continue;
+            }
+
+            let body = response.text().await?;
+            return Err(Error::RateLimited {
+                status: status.as_u16(),
+                body,
+            });
+        }
+
+        let body = response.text().await?;
+        return parse_body(body);
     }
-    req
-        .timeout(timeout)
-        .send()
-        .await?
-        .json()
-        .await
+
+    unreachable!("retry loop always returns")
 }
 
 #[cfg(feature = "wasm")]
@@ -283,20 +658,43 @@ async fn fetch<T>(
     _client: &HttpClient,
     url: Url,
     _timeout: Duration,
-    headers: Option<Headers>
+    headers: Option<Headers>,
+    interceptor: Option<Interceptor>,
+    rate_limiter: &rate_limit::RateLimiter,
+    _retry_policy: &RetryPolicy,
 ) -> Result<T, Error>
 where
     T: DeserializeOwned,
 {
+    rate_limit::throttle(rate_limiter).await;
+
     let mut req = Request::get(url.as_str());
     if let Some(headers) = headers {
         req = req.headers(headers);
     }
-    req
+    if let Some(interceptor) = interceptor {
+        req = interceptor(req);
+    }
+    let body = req
         .send()
         .await?
-        .json()
-        .await
+        .text()
+        .await?;
+
+    parse_body(body)
+}
+
+/// Parse a raw response body, checking for Nominatim's `{"error": ...}`
+/// shape before attempting to deserialize into `T`. This order matters:
+/// `T`s like [`Place`] have every field `#[serde(default)]`/`Option`, so an
+/// `{"error": ...}` body would otherwise happily deserialize into a bogus,
+/// all-default `T` instead of surfacing [`Error::Nominatim`].
+fn parse_body<T: DeserializeOwned>(body: String) -> Result<T, Error> {
+    if let Ok(ErrorResponse { error }) = serde_json::from_str::<ErrorResponse>(&body) {
+        return Err(Error::Nominatim { error });
+    }
+
+    serde_json::from_str::<T>(&body).map_err(|source| Error::Deserialization { source, body })
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, Default, Ord, PartialOrd)]
@@ -325,13 +723,33 @@ pub struct StructuredSearch {
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, Default, Ord, PartialOrd)]
-pub struct ErrorResponse {
+struct ErrorResponse {
     error: String,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum Either<T, U> {
-    Left(T),
-    Right(U),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_body_prefers_nominatim_error_over_a_defaultable_t() {
+        let err = parse_body::<Place>(r#"{"error":"Unable to geocode"}"#.to_string()).unwrap_err();
+        assert!(matches!(err, Error::Nominatim { error } if error == "Unable to geocode"));
+    }
+
+    #[test]
+    fn parse_body_parses_a_valid_place() {
+        let place = parse_body::<Place>(
+            r#"{"place_id":1,"lat":"1.5","lon":"2.5","display_name":"Somewhere"}"#.to_string(),
+        )
+        .unwrap();
+        assert_eq!(place.place_id, 1);
+        assert_eq!(place.lat, 1.5);
+    }
+
+    #[test]
+    fn parse_body_surfaces_deserialization_error_for_garbage() {
+        let err = parse_body::<Place>("not json".to_string()).unwrap_err();
+        assert!(matches!(err, Error::Deserialization { .. }));
+    }
 }
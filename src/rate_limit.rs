@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+#[cfg(feature = "reqwest")]
+use std::{sync::Arc, time::Instant};
+#[cfg(feature = "reqwest")]
+use tokio::sync::Mutex;
+
+/// Minimum spacing enforced between outgoing requests, honoring Nominatim's
+/// "no more than 1 request per second" usage policy for the public OSM host.
+///
+/// Set with [`crate::Client::set_rate_limit`].
+#[cfg(feature = "reqwest")]
+pub(crate) type RateLimiter = Arc<Mutex<RateLimiterState>>;
+#[cfg(feature = "wasm")]
+pub(crate) type RateLimiter = ();
+
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RateLimiterState {
+    min_interval: Duration,
+    last_dispatch: Option<Instant>,
+}
+
+#[cfg(feature = "reqwest")]
+pub(crate) fn new_rate_limiter(min_interval: Duration) -> RateLimiter {
+    Arc::new(Mutex::new(RateLimiterState {
+        min_interval,
+        last_dispatch: None,
+    }))
+}
+#[cfg(feature = "wasm")]
+pub(crate) fn new_rate_limiter(_min_interval: Duration) -> RateLimiter {}
+
+/// Block until `min_interval` has elapsed since the last dispatched request.
+#[cfg(feature = "reqwest")]
+pub(crate) async fn throttle(limiter: &RateLimiter) {
+    let mut state = limiter.lock().await;
+    if let Some(prev) = state.last_dispatch {
+        let elapsed = prev.elapsed();
+        if elapsed < state.min_interval {
+            tokio::time::sleep(state.min_interval - elapsed).await;
+        }
+    }
+    state.last_dispatch = Some(Instant::now());
+}
+#[cfg(feature = "wasm")]
+pub(crate) async fn throttle(_limiter: &RateLimiter) {}
+
+/// Retry policy for transient `429 Too Many Requests` / `503 Service
+/// Unavailable` responses.
+///
+/// Set with [`crate::Client::set_retry_policy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Number of retries attempted after the initial request.
+    pub max_attempts: u32,
+    /// Backoff before the first retry, doubled after each subsequent one.
+    pub initial_backoff: Duration,
+    /// Upper bound on the backoff between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(4),
+        }
+    }
+}
+
+/// Double `current`, capped at `max`. Used to advance the backoff between
+/// retries when the `429`/`503` response carries no `Retry-After` header.
+pub(crate) fn next_backoff(current: Duration, max: Duration) -> Duration {
+    (current * 2).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_backoff_doubles() {
+        assert_eq!(
+            next_backoff(Duration::from_secs(1), Duration::from_secs(100)),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn next_backoff_caps_at_max() {
+        assert_eq!(
+            next_backoff(Duration::from_secs(3), Duration::from_secs(4)),
+            Duration::from_secs(4)
+        );
+    }
+}
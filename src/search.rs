@@ -0,0 +1,183 @@
+use serde::{Serialize, Serializer};
+
+use crate::{Client, Error, Place};
+
+/// Serialize `Option<bool>` as Nominatim's `0`/`1` convention (the same one
+/// `search_with_options`/`reverse_with_options`/`lookup_with_options` already
+/// hardcode for `addressdetails`/`extratags`), rather than serde's default
+/// `"true"`/`"false"` strings, which the API silently ignores.
+fn serialize_bool_as_int<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_u8(*value as u8),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Query parameters shared by [`SearchBuilder`] and, optionally, the
+/// `reverse`/`lookup` endpoints.
+///
+/// All fields are omitted from the request unless explicitly set, matching
+/// the `skip_serializing_if` pattern used by [`crate::StructuredSearch`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SearchOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    countrycodes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    viewbox: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_bool_as_int")]
+    bounded: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_place_ids: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_bool_as_int")]
+    dedupe: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layer: Option<String>,
+    #[serde(rename = "featureType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    featuretype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_bool_as_int")]
+    namedetails: Option<bool>,
+    #[serde(rename = "accept-language")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accept_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_bool_as_int")]
+    polygon_geojson: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    polygon_threshold: Option<f64>,
+}
+
+/// A fluent builder for the `/search` endpoint, exposing the optional query
+/// parameters that [`Client::search`] leaves unset. `addressdetails` and
+/// `extratags` are always sent as `1`, as with [`Client::search`].
+///
+/// Built with [`Client::search_builder`] and dispatched with [`Self::send`].
+#[derive(Debug, Clone)]
+pub struct SearchBuilder {
+    client: Client,
+    query: String,
+    options: SearchOptions,
+}
+
+impl SearchBuilder {
+    pub(crate) fn new(client: Client, query: impl Into<String>) -> Self {
+        Self {
+            client,
+            query: query.into(),
+            options: SearchOptions::default(),
+        }
+    }
+
+    /// Limit the number of returned results.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.options.limit = Some(limit);
+        self
+    }
+
+    /// Restrict the search to a comma-separated list of ISO 3166-1alpha2 country codes.
+    pub fn countrycodes(mut self, countrycodes: impl Into<String>) -> Self {
+        self.options.countrycodes = Some(countrycodes.into());
+        self
+    }
+
+    /// Restrict the search to a viewbox, given as `x1,y1,x2,y2`.
+    ///
+    /// Pair with [`Self::bounded`] to exclude results outside of the box entirely.
+    pub fn viewbox(mut self, viewbox: impl Into<String>) -> Self {
+        self.options.viewbox = Some(viewbox.into());
+        self
+    }
+
+    /// Restrict results to those inside the [`Self::viewbox`].
+    pub fn bounded(mut self, bounded: bool) -> Self {
+        self.options.bounded = Some(bounded);
+        self
+    }
+
+    /// Exclude a comma-separated list of place ids from the results.
+    pub fn exclude_place_ids(mut self, place_ids: impl Into<String>) -> Self {
+        self.options.exclude_place_ids = Some(place_ids.into());
+        self
+    }
+
+    /// Remove duplicate results referring to the same OSM object.
+    pub fn dedupe(mut self, dedupe: bool) -> Self {
+        self.options.dedupe = Some(dedupe);
+        self
+    }
+
+    /// Restrict the search to one or more layers, e.g. `"address,poi"`.
+    pub fn layer(mut self, layer: impl Into<String>) -> Self {
+        self.options.layer = Some(layer.into());
+        self
+    }
+
+    /// Restrict the search to a feature type: `country`, `state`, `city`, or `settlement`.
+    pub fn featuretype(mut self, featuretype: impl Into<String>) -> Self {
+        self.options.featuretype = Some(featuretype.into());
+        self
+    }
+
+    /// Include a breakdown of the place's names in the response.
+    pub fn namedetails(mut self, namedetails: bool) -> Self {
+        self.options.namedetails = Some(namedetails);
+        self
+    }
+
+    /// Override the `Accept-Language` used to localize results, e.g. `"de"` or `"en-US,en"`.
+    pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+        self.options.accept_language = Some(accept_language.into());
+        self
+    }
+
+    /// Include the place's geometry as GeoJSON.
+    pub fn polygon_geojson(mut self, polygon_geojson: bool) -> Self {
+        self.options.polygon_geojson = Some(polygon_geojson);
+        self
+    }
+
+    /// Simplify the returned polygon geometry to within this tolerance (in degrees).
+    pub fn polygon_threshold(mut self, polygon_threshold: f64) -> Self {
+        self.options.polygon_threshold = Some(polygon_threshold);
+        self
+    }
+
+    /// Send the search request, returning the matching [`Place`]s.
+    pub async fn send(self) -> Result<Vec<Place>, Error> {
+        self.client.search_with_options(&self.query, &self.options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_options_encode_to_empty_string() {
+        let options = SearchOptions::default();
+        assert_eq!(serde_urlencoded::to_string(&options).unwrap(), "");
+    }
+
+    #[test]
+    fn bools_encode_as_zero_or_one() {
+        let options = SearchOptions {
+            bounded: Some(true),
+            dedupe: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(serde_urlencoded::to_string(&options).unwrap(), "bounded=1&dedupe=0");
+    }
+
+    #[test]
+    fn featuretype_encodes_as_feature_type() {
+        let options = SearchOptions {
+            featuretype: Some("city".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(serde_urlencoded::to_string(&options).unwrap(), "featureType=city");
+    }
+}